@@ -0,0 +1,467 @@
+//! Parsing and license-normalization logic for Syft SBOM JSON output.
+//!
+//! This crate exposes the Syft data model ([`SyftOutput`], [`Artifact`],
+//! [`SyftLicense`]), SPDX expression parsing ([`spdx`]), license metadata
+//! lookup ([`license_db`]), fuzzy license-text matching ([`license_fuzzy`]),
+//! and policy evaluation ([`policy`]) so other tools can embed Syft SBOM
+//! parsing and license normalization in their own pipelines without
+//! shelling out to the CLI binary.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use tabled::Tabled;
+
+pub mod license_db;
+pub mod license_fuzzy;
+pub mod policy;
+pub mod spdx;
+
+/// Errors produced anywhere in the crate's parsing, normalization, or
+/// policy-evaluation pipeline.
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    Csv(csv::Error),
+    Policy(policy::PolicyError),
+    /// The Syft document had no `artifacts` array.
+    NoArtifacts,
+    /// One or more artifacts violated the supplied license policy.
+    PolicyViolation(Vec<policy::Violation>),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "I/O error: {}", e),
+            Error::Json(e) => write!(f, "error parsing Syft JSON: {}", e),
+            Error::Csv(e) => write!(f, "error writing CSV: {}", e),
+            Error::Policy(e) => write!(f, "{}", e),
+            Error::NoArtifacts => write!(f, "no artifacts found in Syft output"),
+            Error::PolicyViolation(violations) => {
+                writeln!(f, "license policy violations found:")?;
+                for violation in violations {
+                    writeln!(f, "  {}", violation)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Json(e)
+    }
+}
+
+impl From<csv::Error> for Error {
+    fn from(e: csv::Error) -> Self {
+        Error::Csv(e)
+    }
+}
+
+impl From<policy::PolicyError> for Error {
+    fn from(e: policy::PolicyError) -> Self {
+        Error::Policy(e)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SyftOutput {
+    pub artifacts: Option<Vec<Artifact>>,
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Artifact {
+    pub name: Option<String>,
+    pub version: Option<String>,
+    #[serde(rename = "type")]
+    pub artifact_type: Option<String>,
+    pub licenses: Option<Vec<SyftLicense>>,
+    #[serde(rename = "purl")]
+    pub package_url: Option<String>,
+    pub language: Option<String>,
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum SyftLicense {
+    Simple(String),
+    Detailed {
+        value: Option<String>,
+        #[serde(rename = "spdxExpression")]
+        spdx_expression: Option<String>,
+        #[serde(rename = "type")]
+        license_type: Option<String>,
+        #[serde(flatten)]
+        other: HashMap<String, serde_json::Value>,
+    },
+}
+
+#[derive(Debug, Serialize, Tabled)]
+pub struct CsvRecord {
+    pub name: String,
+    pub version: String,
+    #[tabled(rename = "Type")]
+    pub artifact_type: String,
+    pub licenses: String,
+    #[tabled(rename = "Category")]
+    pub license_category: String,
+    #[tabled(rename = "Full Name")]
+    pub license_full_name: String,
+    #[tabled(rename = "Deprecated")]
+    pub license_deprecated: String,
+    #[tabled(rename = "Confidence")]
+    pub license_confidence: String,
+}
+
+/// Parses Syft SBOM JSON text into a [`SyftOutput`].
+pub fn parse_syft(content: &str) -> Result<SyftOutput, Error> {
+    serde_json::from_str(content).map_err(Error::from)
+}
+
+/// A single resolved license entry. `confidence` is `Some` when `text` was
+/// guessed by fuzzy-matching raw license text rather than taken from a
+/// declared SPDX expression.
+struct ResolvedLicense {
+    text: String,
+    confidence: Option<f64>,
+}
+
+/// Normalizes a declared (non-fuzzy) license string, canonicalizing it if
+/// it parses as an SPDX expression.
+fn normalize_declared(raw: &str) -> Option<ResolvedLicense> {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+    let text = match spdx::parse(trimmed) {
+        Ok(expr) => expr.to_string(),
+        Err(_) => trimmed.to_string(),
+    };
+    Some(ResolvedLicense { text, confidence: None })
+}
+
+/// Fuzzy-matches raw license text against the bundled templates, falling
+/// back to "Unknown" below the confidence threshold.
+fn fuzzy_resolve(text: &str) -> ResolvedLicense {
+    match license_fuzzy::best_match(text, license_fuzzy::DEFAULT_THRESHOLD) {
+        Some(m) => ResolvedLicense {
+            text: m.spdx_id,
+            confidence: Some(m.confidence),
+        },
+        None => ResolvedLicense {
+            text: "Unknown".to_string(),
+            confidence: None,
+        },
+    }
+}
+
+fn resolve_artifact_licenses(artifact: &Artifact) -> Vec<ResolvedLicense> {
+    match &artifact.licenses {
+        Some(license_vec) => license_vec
+            .iter()
+            .filter_map(|license| match license {
+                SyftLicense::Simple(license_str) => normalize_declared(license_str),
+                SyftLicense::Detailed {
+                    value,
+                    spdx_expression,
+                    license_type: _,
+                    other: _,
+                } => {
+                    let declared = spdx_expression
+                        .as_deref()
+                        .map(str::trim)
+                        .filter(|s| !s.is_empty());
+
+                    match declared {
+                        Some(expr) => normalize_declared(expr),
+                        None => {
+                            // No clean spdxExpression: Syft gave us raw
+                            // license text instead, so fuzzy-match it.
+                            let text = value.as_deref().map(str::trim).filter(|s| !s.is_empty());
+                            text.map(fuzzy_resolve)
+                        }
+                    }
+                }
+            })
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Flattens an artifact's resolved licenses into their leaf license ids and
+/// parallel fuzzy-match confidences (splitting `AND`/`OR`/`WITH` expressions
+/// and semicolon-separated lists).
+fn flatten_artifact_licenses(artifact: &Artifact) -> (Vec<String>, Vec<Option<f64>>) {
+    let resolved = resolve_artifact_licenses(artifact);
+
+    let mut ids = Vec::new();
+    let mut confidences = Vec::new();
+
+    for license in &resolved {
+        if license.confidence.is_some() {
+            // A fuzzy-matched guess is already a single resolved id.
+            ids.push(license.text.clone());
+            confidences.push(license.confidence);
+        } else if let Ok(expr) = spdx::parse(&license.text) {
+            // Flatten the parsed AST's leaf licenses instead of naively
+            // string-splitting on operators.
+            for leaf in expr.flatten_licenses() {
+                ids.push(leaf);
+                confidences.push(None);
+            }
+        } else if license.text.contains(';') {
+            // Handle semicolon-separated licenses
+            for part in license.text.split(';') {
+                let trimmed = part.trim();
+                if !trimmed.is_empty() {
+                    ids.push(trimmed.to_string());
+                    confidences.push(None);
+                }
+            }
+        } else {
+            // Single license
+            ids.push(license.text.clone());
+            confidences.push(None);
+        }
+    }
+
+    (ids, confidences)
+}
+
+/// Extracts the flattened, normalized license identifiers declared (or
+/// fuzzy-matched) on an artifact.
+pub fn extract_licenses(artifact: &Artifact) -> Vec<String> {
+    flatten_artifact_licenses(artifact).0
+}
+
+/// Builds one [`CsvRecord`] per artifact, joining multi-valued fields with
+/// `separator` (e.g. `"\n"` for table display, `"; "` for CSV export).
+pub fn build_records(artifacts: &[Artifact], separator: &str) -> Vec<CsvRecord> {
+    artifacts
+        .iter()
+        .map(|artifact| {
+            let (all_licenses, confidences) = flatten_artifact_licenses(artifact);
+
+            let licenses_formatted = if all_licenses.is_empty() {
+                "None".to_string()
+            } else {
+                all_licenses.join(separator)
+            };
+
+            let metadata: Vec<Option<license_db::LicenseInfo>> =
+                all_licenses.iter().map(|id| license_db::lookup(id)).collect();
+
+            let license_category = if metadata.is_empty() {
+                "None".to_string()
+            } else {
+                metadata
+                    .iter()
+                    .map(|info| info.map(|i| i.category.to_string()).unwrap_or_else(|| "Unknown".to_string()))
+                    .collect::<Vec<_>>()
+                    .join(separator)
+            };
+            let license_full_name = if metadata.is_empty() {
+                "None".to_string()
+            } else {
+                metadata
+                    .iter()
+                    .map(|info| info.map(|i| i.full_name.to_string()).unwrap_or_else(|| "Unknown".to_string()))
+                    .collect::<Vec<_>>()
+                    .join(separator)
+            };
+            let license_deprecated = if metadata.is_empty() {
+                "None".to_string()
+            } else {
+                metadata
+                    .iter()
+                    .map(|info| match info {
+                        Some(i) if i.deprecated => "Yes".to_string(),
+                        Some(_) => "No".to_string(),
+                        None => "Unknown".to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(separator)
+            };
+
+            // "-" marks a declared (non-fuzzy) license or the "Unknown"
+            // fallback of a failed fuzzy match; fuzzy guesses show their
+            // Dice-coefficient confidence.
+            let license_confidence = if confidences.is_empty() {
+                "None".to_string()
+            } else {
+                confidences
+                    .iter()
+                    .map(|c| match c {
+                        Some(score) => format!("{:.2}", score),
+                        None => "-".to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(separator)
+            };
+
+            CsvRecord {
+                name: artifact.name.clone().unwrap_or_else(|| "Unknown".to_string()),
+                version: artifact.version.clone().unwrap_or_else(|| "Unknown".to_string()),
+                artifact_type: artifact.artifact_type.clone().unwrap_or_else(|| "Unknown".to_string()),
+                licenses: licenses_formatted,
+                license_category,
+                license_full_name,
+                license_deprecated,
+                license_confidence,
+            }
+        })
+        .collect()
+}
+
+/// Writes `records` as CSV to `writer`.
+pub fn write_csv<W: std::io::Write>(writer: W, records: &[CsvRecord]) -> Result<(), Error> {
+    let mut wtr = csv::Writer::from_writer(writer);
+
+    wtr.write_record(&[
+        "Name",
+        "Version",
+        "Type",
+        "Licenses",
+        "Category",
+        "Full Name",
+        "Deprecated",
+        "Confidence",
+    ])?;
+
+    for record in records {
+        wtr.serialize(record)?;
+    }
+
+    wtr.flush().map_err(Error::Io)?;
+    Ok(())
+}
+
+/// Evaluates every artifact's resolved license (after applying any matching
+/// clarification) against `loaded_policy`, returning
+/// [`Error::PolicyViolation`] if any artifact fails.
+pub fn check_policy(artifacts: &[Artifact], loaded_policy: &policy::Policy) -> Result<(), Error> {
+    let mut violations = Vec::new();
+
+    for artifact in artifacts {
+        let name = artifact.name.clone().unwrap_or_else(|| "Unknown".to_string());
+        let version = artifact.version.clone().unwrap_or_else(|| "Unknown".to_string());
+
+        let clarified = policy::clarified_license(loaded_policy, &name, Some(&version))?;
+
+        let exprs: Vec<spdx::SpdxExpr> = match clarified {
+            // A clarification overrides every license Syft reported for
+            // this package, so only its single expression is checked.
+            Some(expr) => vec![expr],
+            // Otherwise check every resolved license entry, not just the
+            // first one that happens to parse — Syft commonly reports
+            // multiple separate license entries on one artifact.
+            None => {
+                let resolved = resolve_artifact_licenses(artifact);
+                let parsed: Vec<spdx::SpdxExpr> = resolved
+                    .iter()
+                    .filter_map(|r| spdx::parse(&r.text).ok())
+                    .collect();
+
+                // Nothing resolved to a known SPDX expression — Syft
+                // reported `NOASSERTION`/`NONE`, an unrecognized raw
+                // string, or no license at all. Treat that as having no
+                // usable license information and fall back to the
+                // policy's default action instead of silently passing.
+                if parsed.is_empty() && loaded_policy.default == policy::DefaultAction::Deny {
+                    let license = resolved
+                        .first()
+                        .map(|r| r.text.clone())
+                        .unwrap_or_else(|| "NOASSERTION".to_string());
+                    violations.push(policy::Violation {
+                        package: name.clone(),
+                        version: version.clone(),
+                        license,
+                    });
+                }
+
+                parsed
+            }
+        };
+
+        for expr in &exprs {
+            if let Some(violation) = policy::evaluate(loaded_policy, &name, &version, expr) {
+                violations.push(violation);
+            }
+        }
+    }
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::PolicyViolation(violations))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use policy::DefaultAction;
+
+    fn policy_with_default(default: DefaultAction) -> policy::Policy {
+        policy::Policy {
+            allow: Vec::new(),
+            deny: Vec::new(),
+            default,
+            clarifications: Vec::new(),
+        }
+    }
+
+    fn artifact_with_license(license: Option<&str>) -> Artifact {
+        let licenses_json = match license {
+            Some(license) => format!(r#""licenses": ["{}"],"#, license),
+            None => String::new(),
+        };
+        let json = format!(
+            r#"{{"name": "example", "version": "1.0.0", {} "type": "npm"}}"#,
+            licenses_json
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn noassertion_license_is_denied_under_default_deny() {
+        let policy = policy_with_default(DefaultAction::Deny);
+        let artifact = artifact_with_license(Some("NOASSERTION"));
+
+        let err = check_policy(&[artifact], &policy).unwrap_err();
+        assert!(matches!(err, Error::PolicyViolation(_)));
+    }
+
+    #[test]
+    fn missing_license_is_denied_under_default_deny() {
+        let policy = policy_with_default(DefaultAction::Deny);
+        let artifact = artifact_with_license(None);
+
+        let err = check_policy(&[artifact], &policy).unwrap_err();
+        assert!(matches!(err, Error::PolicyViolation(_)));
+    }
+
+    #[test]
+    fn missing_license_passes_under_default_allow() {
+        let policy = policy_with_default(DefaultAction::Allow);
+        let artifact = artifact_with_license(None);
+
+        assert!(check_policy(&[artifact], &policy).is_ok());
+    }
+}
@@ -0,0 +1,271 @@
+//! A static lookup table mapping SPDX license identifiers to the kind of
+//! metadata license databases expose per key: category, canonical full name,
+//! and whether the identifier is deprecated. Lets a user scanning a Syft SBOM
+//! see at a glance whether they've pulled in a strong-copyleft or
+//! deprecated-identifier dependency without cross-referencing an external
+//! database.
+
+use std::fmt;
+
+/// How restrictive a license's terms are for derivative and combined works.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LicenseCategory {
+    Permissive,
+    WeakCopyleft,
+    Copyleft,
+    Proprietary,
+    PublicDomain,
+    Other,
+}
+
+impl fmt::Display for LicenseCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            LicenseCategory::Permissive => "Permissive",
+            LicenseCategory::WeakCopyleft => "Weak Copyleft",
+            LicenseCategory::Copyleft => "Copyleft",
+            LicenseCategory::Proprietary => "Proprietary",
+            LicenseCategory::PublicDomain => "Public Domain",
+            LicenseCategory::Other => "Other",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Metadata about a single SPDX license identifier.
+#[derive(Debug, Clone, Copy)]
+pub struct LicenseInfo {
+    pub full_name: &'static str,
+    pub category: LicenseCategory,
+    pub deprecated: bool,
+}
+
+/// Looks up metadata for a known SPDX license identifier. Returns `None` for
+/// identifiers outside the bundled dataset.
+pub fn lookup(id: &str) -> Option<LicenseInfo> {
+    LICENSE_DB
+        .iter()
+        .find(|(key, _)| *key == id)
+        .map(|(_, info)| *info)
+}
+
+use LicenseCategory::*;
+
+const LICENSE_DB: &[(&str, LicenseInfo)] = &[
+    (
+        "0BSD",
+        LicenseInfo { full_name: "BSD Zero Clause License", category: Permissive, deprecated: false },
+    ),
+    (
+        "AFL-3.0",
+        LicenseInfo { full_name: "Academic Free License v3.0", category: Permissive, deprecated: false },
+    ),
+    (
+        "AGPL-3.0-only",
+        LicenseInfo { full_name: "GNU Affero General Public License v3.0 only", category: Copyleft, deprecated: false },
+    ),
+    (
+        "AGPL-3.0-or-later",
+        LicenseInfo { full_name: "GNU Affero General Public License v3.0 or later", category: Copyleft, deprecated: false },
+    ),
+    (
+        "Apache-1.1",
+        LicenseInfo { full_name: "Apache License 1.1", category: Permissive, deprecated: false },
+    ),
+    (
+        "Apache-2.0",
+        LicenseInfo { full_name: "Apache License 2.0", category: Permissive, deprecated: false },
+    ),
+    (
+        "Artistic-2.0",
+        LicenseInfo { full_name: "Artistic License 2.0", category: WeakCopyleft, deprecated: false },
+    ),
+    (
+        "BSD-2-Clause",
+        LicenseInfo { full_name: "BSD 2-Clause \"Simplified\" License", category: Permissive, deprecated: false },
+    ),
+    (
+        "BSD-3-Clause",
+        LicenseInfo { full_name: "BSD 3-Clause \"New\" or \"Revised\" License", category: Permissive, deprecated: false },
+    ),
+    (
+        "BSD-4-Clause",
+        LicenseInfo { full_name: "BSD 4-Clause \"Original\" or \"Old\" License", category: Permissive, deprecated: false },
+    ),
+    (
+        "BSL-1.0",
+        LicenseInfo { full_name: "Boost Software License 1.0", category: Permissive, deprecated: false },
+    ),
+    (
+        "CC0-1.0",
+        LicenseInfo { full_name: "Creative Commons Zero v1.0 Universal", category: PublicDomain, deprecated: false },
+    ),
+    (
+        "CC-BY-4.0",
+        LicenseInfo { full_name: "Creative Commons Attribution 4.0 International", category: Permissive, deprecated: false },
+    ),
+    (
+        "CC-BY-SA-4.0",
+        LicenseInfo { full_name: "Creative Commons Attribution Share Alike 4.0 International", category: WeakCopyleft, deprecated: false },
+    ),
+    (
+        "CDDL-1.0",
+        LicenseInfo { full_name: "Common Development and Distribution License 1.0", category: WeakCopyleft, deprecated: false },
+    ),
+    (
+        "CDDL-1.1",
+        LicenseInfo { full_name: "Common Development and Distribution License 1.1", category: WeakCopyleft, deprecated: false },
+    ),
+    (
+        "CPL-1.0",
+        LicenseInfo { full_name: "Common Public License 1.0", category: WeakCopyleft, deprecated: false },
+    ),
+    (
+        "EPL-1.0",
+        LicenseInfo { full_name: "Eclipse Public License 1.0", category: WeakCopyleft, deprecated: false },
+    ),
+    (
+        "EPL-2.0",
+        LicenseInfo { full_name: "Eclipse Public License 2.0", category: WeakCopyleft, deprecated: false },
+    ),
+    (
+        "EUPL-1.1",
+        LicenseInfo { full_name: "European Union Public License 1.1", category: WeakCopyleft, deprecated: false },
+    ),
+    (
+        "EUPL-1.2",
+        LicenseInfo { full_name: "European Union Public License 1.2", category: WeakCopyleft, deprecated: false },
+    ),
+    (
+        "GFDL-1.3-only",
+        LicenseInfo { full_name: "GNU Free Documentation License v1.3 only", category: Copyleft, deprecated: false },
+    ),
+    (
+        "GPL-1.0-only",
+        LicenseInfo { full_name: "GNU General Public License v1.0 only", category: Copyleft, deprecated: true },
+    ),
+    (
+        "GPL-1.0-or-later",
+        LicenseInfo { full_name: "GNU General Public License v1.0 or later", category: Copyleft, deprecated: true },
+    ),
+    (
+        "GPL-2.0-only",
+        LicenseInfo { full_name: "GNU General Public License v2.0 only", category: Copyleft, deprecated: false },
+    ),
+    (
+        "GPL-2.0-or-later",
+        LicenseInfo { full_name: "GNU General Public License v2.0 or later", category: Copyleft, deprecated: false },
+    ),
+    (
+        "GPL-3.0-only",
+        LicenseInfo { full_name: "GNU General Public License v3.0 only", category: Copyleft, deprecated: false },
+    ),
+    (
+        "GPL-3.0-or-later",
+        LicenseInfo { full_name: "GNU General Public License v3.0 or later", category: Copyleft, deprecated: false },
+    ),
+    (
+        "ISC",
+        LicenseInfo { full_name: "ISC License", category: Permissive, deprecated: false },
+    ),
+    (
+        "LGPL-2.0-only",
+        LicenseInfo { full_name: "GNU Library General Public License v2 only", category: WeakCopyleft, deprecated: true },
+    ),
+    (
+        "LGPL-2.0-or-later",
+        LicenseInfo { full_name: "GNU Library General Public License v2 or later", category: WeakCopyleft, deprecated: true },
+    ),
+    (
+        "LGPL-2.1-only",
+        LicenseInfo { full_name: "GNU Lesser General Public License v2.1 only", category: WeakCopyleft, deprecated: false },
+    ),
+    (
+        "LGPL-2.1-or-later",
+        LicenseInfo { full_name: "GNU Lesser General Public License v2.1 or later", category: WeakCopyleft, deprecated: false },
+    ),
+    (
+        "LGPL-3.0-only",
+        LicenseInfo { full_name: "GNU Lesser General Public License v3.0 only", category: WeakCopyleft, deprecated: false },
+    ),
+    (
+        "LGPL-3.0-or-later",
+        LicenseInfo { full_name: "GNU Lesser General Public License v3.0 or later", category: WeakCopyleft, deprecated: false },
+    ),
+    (
+        "MIT",
+        LicenseInfo { full_name: "MIT License", category: Permissive, deprecated: false },
+    ),
+    (
+        "MIT-0",
+        LicenseInfo { full_name: "MIT No Attribution", category: Permissive, deprecated: false },
+    ),
+    (
+        "MPL-1.0",
+        LicenseInfo { full_name: "Mozilla Public License 1.0", category: WeakCopyleft, deprecated: true },
+    ),
+    (
+        "MPL-1.1",
+        LicenseInfo { full_name: "Mozilla Public License 1.1", category: WeakCopyleft, deprecated: true },
+    ),
+    (
+        "MPL-2.0",
+        LicenseInfo { full_name: "Mozilla Public License 2.0", category: WeakCopyleft, deprecated: false },
+    ),
+    (
+        "MS-PL",
+        LicenseInfo { full_name: "Microsoft Public License", category: Permissive, deprecated: false },
+    ),
+    (
+        "MS-RL",
+        LicenseInfo { full_name: "Microsoft Reciprocal License", category: WeakCopyleft, deprecated: false },
+    ),
+    (
+        "NCSA",
+        LicenseInfo { full_name: "University of Illinois/NCSA Open Source License", category: Permissive, deprecated: false },
+    ),
+    (
+        "OFL-1.1",
+        LicenseInfo { full_name: "SIL Open Font License 1.1", category: Permissive, deprecated: false },
+    ),
+    (
+        "OpenSSL",
+        LicenseInfo { full_name: "OpenSSL License", category: Permissive, deprecated: false },
+    ),
+    (
+        "PostgreSQL",
+        LicenseInfo { full_name: "PostgreSQL License", category: Permissive, deprecated: false },
+    ),
+    (
+        "Python-2.0",
+        LicenseInfo { full_name: "Python License 2.0", category: Permissive, deprecated: false },
+    ),
+    (
+        "Ruby",
+        LicenseInfo { full_name: "Ruby License", category: WeakCopyleft, deprecated: false },
+    ),
+    (
+        "Unicode-DFS-2016",
+        LicenseInfo { full_name: "Unicode License Agreement - Data Files and Software (2016)", category: Permissive, deprecated: false },
+    ),
+    (
+        "Unlicense",
+        LicenseInfo { full_name: "The Unlicense", category: PublicDomain, deprecated: false },
+    ),
+    (
+        "Vim",
+        LicenseInfo { full_name: "Vim License", category: WeakCopyleft, deprecated: false },
+    ),
+    (
+        "WTFPL",
+        LicenseInfo { full_name: "Do What The F*ck You Want To Public License", category: PublicDomain, deprecated: false },
+    ),
+    (
+        "X11",
+        LicenseInfo { full_name: "X11 License", category: Permissive, deprecated: false },
+    ),
+    (
+        "Zlib",
+        LicenseInfo { full_name: "zlib License", category: Permissive, deprecated: false },
+    ),
+];
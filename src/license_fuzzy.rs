@@ -0,0 +1,271 @@
+//! Fuzzy-matches raw license text against a bundled set of known license
+//! templates when a Syft license entry has no clean `spdxExpression`.
+//!
+//! Text is normalized (lowercased, whitespace collapsed, punctuation and
+//! copyright/year lines stripped, common boilerplate removed) and compared to
+//! each template using the Sørensen–Dice coefficient over the set of word
+//! bigrams. The highest-scoring template above a configurable threshold wins;
+//! below it, the match is reported as unknown.
+
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+/// Default minimum Dice score for a fuzzy match to be accepted.
+pub const DEFAULT_THRESHOLD: f64 = 0.8;
+
+/// Result of fuzzy-matching a block of license text.
+#[derive(Debug, Clone)]
+pub struct FuzzyMatch {
+    pub spdx_id: String,
+    pub confidence: f64,
+}
+
+/// Lowercases, collapses whitespace, strips punctuation, drops standalone
+/// copyright/year lines, and removes common boilerplate phrases so that
+/// templates and real-world license text compare cleanly.
+///
+/// Only a line that *is* a copyright/rights-reserved notice is dropped, not
+/// any line that merely mentions the word "copyright" in running license
+/// text (e.g. MIT's "the above copyright notice ... shall be included") —
+/// otherwise this would nuke entire single-line license bodies that happen
+/// to reference "copyright" mid-sentence.
+fn normalize(text: &str) -> String {
+    let without_boilerplate_lines: String = text
+        .lines()
+        .filter(|line| !is_copyright_or_rights_line(line))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let lowered = without_boilerplate_lines
+        .to_lowercase()
+        .replace("all rights reserved", " ");
+
+    let stripped: String = lowered
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c.is_whitespace() { c } else { ' ' })
+        .collect();
+
+    stripped.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// True if `line` is itself a copyright or rights-reserved notice, e.g.
+/// `Copyright (c) 2024 Jane Doe` or `All rights reserved.` — as opposed to a
+/// line of license body text that merely contains the word "copyright".
+fn is_copyright_or_rights_line(line: &str) -> bool {
+    let trimmed = line.trim().to_lowercase();
+    if trimmed.is_empty() {
+        return false;
+    }
+    trimmed.starts_with("copyright") || trimmed.trim_end_matches('.') == "all rights reserved"
+}
+
+/// The set of word bigrams ("word pairs") in a normalized string.
+fn bigrams(normalized: &str) -> HashSet<String> {
+    let words: Vec<&str> = normalized.split_whitespace().collect();
+    if words.len() < 2 {
+        return words.iter().map(|w| w.to_string()).collect();
+    }
+    words
+        .windows(2)
+        .map(|pair| format!("{} {}", pair[0], pair[1]))
+        .collect()
+}
+
+/// Sørensen–Dice coefficient: 2·|A ∩ B| / (|A| + |B|).
+fn dice_coefficient(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let intersection = a.intersection(b).count();
+    (2.0 * intersection as f64) / (a.len() + b.len()) as f64
+}
+
+struct Template {
+    spdx_id: &'static str,
+    text: &'static str,
+}
+
+fn templates() -> &'static [Template] {
+    &[
+        Template { spdx_id: "MIT", text: MIT_TEXT },
+        Template { spdx_id: "Apache-2.0", text: APACHE_2_0_TEXT },
+        Template { spdx_id: "BSD-2-Clause", text: BSD_2_CLAUSE_TEXT },
+        Template { spdx_id: "BSD-3-Clause", text: BSD_3_CLAUSE_TEXT },
+        Template { spdx_id: "ISC", text: ISC_TEXT },
+        Template { spdx_id: "Zlib", text: ZLIB_TEXT },
+        Template { spdx_id: "Unlicense", text: UNLICENSE_TEXT },
+        Template { spdx_id: "MPL-2.0", text: MPL_2_0_TEXT },
+    ]
+}
+
+/// The precomputed (normalized, bigram-set) form of each bundled template,
+/// computed once and cached so matching stays fast across many artifacts.
+fn template_bigrams() -> &'static Vec<(&'static str, HashSet<String>)> {
+    static CACHE: OnceLock<Vec<(&'static str, HashSet<String>)>> = OnceLock::new();
+    CACHE.get_or_init(|| {
+        templates()
+            .iter()
+            .map(|t| (t.spdx_id, bigrams(&normalize(t.text))))
+            .collect()
+    })
+}
+
+/// Scores `text` against every bundled template and returns the
+/// highest-scoring SPDX id with its confidence, if at or above `threshold`.
+pub fn best_match(text: &str, threshold: f64) -> Option<FuzzyMatch> {
+    let input_bigrams = bigrams(&normalize(text));
+
+    template_bigrams()
+        .iter()
+        .map(|(id, template_bigrams)| (*id, dice_coefficient(template_bigrams, &input_bigrams)))
+        .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+        .filter(|(_, score)| *score >= threshold)
+        .map(|(id, score)| FuzzyMatch {
+            spdx_id: id.to_string(),
+            confidence: score,
+        })
+}
+
+const MIT_TEXT: &str = "Permission is hereby granted, free of charge, to any person obtaining a copy \
+of this software and associated documentation files (the \"Software\"), to deal \
+in the Software without restriction, including without limitation the rights \
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell \
+copies of the Software, and to permit persons to whom the Software is \
+furnished to do so, subject to the following conditions: The above copyright \
+notice and this permission notice shall be included in all copies or \
+substantial portions of the Software. THE SOFTWARE IS PROVIDED \"AS IS\", \
+WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO \
+THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND \
+NONINFRINGEMENT.";
+
+const APACHE_2_0_TEXT: &str = "Licensed under the Apache License, Version 2.0 (the \"License\"); you may not \
+use this file except in compliance with the License. You may obtain a copy of \
+the License at http://www.apache.org/licenses/LICENSE-2.0. Unless required by \
+applicable law or agreed to in writing, software distributed under the \
+License is distributed on an \"AS IS\" BASIS, WITHOUT WARRANTIES OR CONDITIONS \
+OF ANY KIND, either express or implied. See the License for the specific \
+language governing permissions and limitations under the License.";
+
+const BSD_2_CLAUSE_TEXT: &str = "Redistribution and use in source and binary forms, with or without \
+modification, are permitted provided that the following conditions are met: \
+1. Redistributions of source code must retain the above copyright notice, \
+this list of conditions and the following disclaimer. 2. Redistributions in \
+binary form must reproduce the above copyright notice, this list of \
+conditions and the following disclaimer in the documentation and/or other \
+materials provided with the distribution. THIS SOFTWARE IS PROVIDED BY THE \
+COPYRIGHT HOLDERS AND CONTRIBUTORS \"AS IS\" AND ANY EXPRESS OR IMPLIED \
+WARRANTIES ARE DISCLAIMED.";
+
+const BSD_3_CLAUSE_TEXT: &str = "Redistribution and use in source and binary forms, with or without \
+modification, are permitted provided that the following conditions are met: \
+1. Redistributions of source code must retain the above copyright notice, \
+this list of conditions and the following disclaimer. 2. Redistributions in \
+binary form must reproduce the above copyright notice, this list of \
+conditions and the following disclaimer in the documentation and/or other \
+materials provided with the distribution. 3. Neither the name of the \
+copyright holder nor the names of its contributors may be used to endorse or \
+promote products derived from this software without specific prior written \
+permission. THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND \
+CONTRIBUTORS \"AS IS\" AND ANY EXPRESS OR IMPLIED WARRANTIES ARE DISCLAIMED.";
+
+const ISC_TEXT: &str = "Permission to use, copy, modify, and/or distribute this software for any \
+purpose with or without fee is hereby granted, provided that the above \
+copyright notice and this permission notice appear in all copies. THE \
+SOFTWARE IS PROVIDED \"AS IS\" AND THE AUTHOR DISCLAIMS ALL WARRANTIES WITH \
+REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY \
+AND FITNESS.";
+
+const ZLIB_TEXT: &str = "This software is provided 'as-is', without any express or implied warranty. \
+In no event will the authors be held liable for any damages arising from the \
+use of this software. Permission is granted to anyone to use this software \
+for any purpose, including commercial applications, and to alter it and \
+redistribute it freely, subject to the following restrictions: 1. The origin \
+of this software must not be misrepresented. 2. Altered source versions must \
+be plainly marked as such. 3. This notice may not be removed or altered from \
+any source distribution.";
+
+const UNLICENSE_TEXT: &str = "This is free and unencumbered software released into the public domain. \
+Anyone is free to copy, modify, publish, use, compile, sell, or distribute \
+this software, either in source code form or as a compiled binary, for any \
+purpose, commercial or non-commercial, and by any means. In jurisdictions \
+that recognize copyright laws, the author or authors of this software \
+dedicate any and all copyright interest in the software to the public \
+domain.";
+
+const MPL_2_0_TEXT: &str = "This Source Code Form is subject to the terms of the Mozilla Public License, \
+v. 2.0. If a copy of the MPL was not distributed with this file, you can \
+obtain one at http://mozilla.org/MPL/2.0/. Covered Software is provided under \
+this License on an \"as is\" basis, without warranty of any kind, either \
+expressed, implied, or statutory.";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_template_text_matches_with_high_confidence() {
+        let text = format!("Copyright (c) 2024 Jane Doe\n\n{}", MIT_TEXT);
+        let result = best_match(&text, DEFAULT_THRESHOLD).expect("should match MIT");
+
+        assert_eq!(result.spdx_id, "MIT");
+        assert!(result.confidence > 0.95, "confidence was {}", result.confidence);
+    }
+
+    #[test]
+    fn unrelated_text_falls_back_to_none() {
+        let text = "This is just a regular README describing how to install the package.";
+        assert!(best_match(text, DEFAULT_THRESHOLD).is_none());
+    }
+
+    #[test]
+    fn score_below_threshold_is_rejected() {
+        let input_bigrams = bigrams(&normalize(MIT_TEXT));
+        let mit_bigrams = template_bigrams()
+            .iter()
+            .find(|(id, _)| *id == "MIT")
+            .map(|(_, b)| b.clone())
+            .unwrap();
+        assert_eq!(dice_coefficient(&input_bigrams, &mit_bigrams), 1.0);
+
+        // A threshold above the best possible score rejects even an exact
+        // match.
+        assert!(best_match(MIT_TEXT, 1.01).is_none());
+    }
+
+    #[test]
+    fn normalize_strips_boilerplate_and_punctuation() {
+        let text = "Copyright (c) 2024 Jane Doe\nAll rights reserved.\nMIT License granted herein.";
+        let normalized = normalize(text);
+
+        assert!(!normalized.contains("copyright"));
+        assert!(!normalized.contains("jane doe"));
+        assert!(normalized.contains("mit license granted herein"));
+    }
+
+    #[test]
+    fn dice_coefficient_is_symmetric_and_bounded() {
+        let a = bigrams(&normalize(MIT_TEXT));
+        let b = bigrams(&normalize(APACHE_2_0_TEXT));
+
+        let forward = dice_coefficient(&a, &b);
+        let backward = dice_coefficient(&b, &a);
+
+        assert_eq!(forward, backward);
+        assert!((0.0..=1.0).contains(&forward));
+    }
+
+    #[test]
+    fn every_bundled_template_has_a_non_empty_bigram_set() {
+        for (spdx_id, bigrams) in template_bigrams() {
+            assert!(
+                !bigrams.is_empty(),
+                "{} normalized to an empty bigram set — its template text was \
+                 likely nuked by the boilerplate-line filter",
+                spdx_id
+            );
+        }
+    }
+}
@@ -0,0 +1,308 @@
+//! License policy evaluation: allow/deny lists, a default action for
+//! unmatched licenses, and per-package clarifications that override a
+//! misreported or missing Syft license with a known-correct SPDX expression.
+
+use serde::Deserialize;
+use std::fmt;
+use std::fs;
+
+use crate::spdx::{self, SpdxExpr};
+
+/// The action to take for a license that matches neither the allow list nor
+/// the deny list.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DefaultAction {
+    Allow,
+    Deny,
+}
+
+/// A correction for a package whose reported license is wrong or missing.
+/// `version` is a semver requirement (e.g. `"^1.2"`, `">=2.0.0, <3.0.0"`)
+/// so a single clarification can cover a range of releases.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Clarification {
+    pub package: String,
+    #[serde(deserialize_with = "deserialize_version_req")]
+    pub version: semver::VersionReq,
+    pub license: String,
+}
+
+fn deserialize_version_req<'de, D>(deserializer: D) -> Result<semver::VersionReq, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let raw = String::deserialize(deserializer)?;
+    semver::VersionReq::parse(&raw).map_err(serde::de::Error::custom)
+}
+
+/// A policy config loaded from TOML via `--policy <file>`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Policy {
+    #[serde(default)]
+    pub allow: Vec<String>,
+    #[serde(default)]
+    pub deny: Vec<String>,
+    #[serde(default = "default_action_deny")]
+    pub default: DefaultAction,
+    #[serde(default)]
+    pub clarifications: Vec<Clarification>,
+}
+
+fn default_action_deny() -> DefaultAction {
+    DefaultAction::Deny
+}
+
+/// Errors produced while loading or evaluating a policy.
+#[derive(Debug)]
+pub enum PolicyError {
+    Io(std::io::Error),
+    Toml(toml::de::Error),
+    /// A clarification's `license` field failed to parse as an SPDX
+    /// expression, for the named package/version.
+    Clarification(String, String, spdx::SpdxError),
+    /// `package` has a matching clarification, but `version` could not be
+    /// parsed as SemVer, so the clarification could not be checked.
+    InvalidClarificationVersion(String, String),
+}
+
+impl fmt::Display for PolicyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolicyError::Io(e) => write!(f, "error reading policy file: {}", e),
+            PolicyError::Toml(e) => write!(f, "error parsing policy file: {}", e),
+            PolicyError::Clarification(package, version, e) => write!(
+                f,
+                "error in clarification for {}@{}: {}",
+                package, version, e
+            ),
+            PolicyError::InvalidClarificationVersion(package, version) => write!(
+                f,
+                "package {} has a clarification but its version '{}' is not valid SemVer, \
+                 so the clarification could not be checked",
+                package, version
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PolicyError {}
+
+impl From<std::io::Error> for PolicyError {
+    fn from(e: std::io::Error) -> Self {
+        PolicyError::Io(e)
+    }
+}
+
+impl From<toml::de::Error> for PolicyError {
+    fn from(e: toml::de::Error) -> Self {
+        PolicyError::Toml(e)
+    }
+}
+
+/// Loads and parses a policy config from `path`.
+pub fn load_policy(path: &str) -> Result<Policy, PolicyError> {
+    let contents = fs::read_to_string(path)?;
+    let policy: Policy = toml::from_str(&contents)?;
+    Ok(policy)
+}
+
+/// If `package`/`version` matches a clarification, returns the clarified
+/// SPDX expression instead of Syft's reported one. A leading `v` (as in
+/// Go module versions like `v1.2.3`) is stripped before parsing. If
+/// `package` has a clarification but `version` still can't be parsed as
+/// SemVer, returns [`PolicyError::InvalidClarificationVersion`] rather than
+/// silently falling through to the unclarified path.
+pub fn clarified_license(
+    policy: &Policy,
+    package: &str,
+    version: Option<&str>,
+) -> Result<Option<SpdxExpr>, PolicyError> {
+    let Some(version) = version else {
+        return Ok(None);
+    };
+
+    let has_matching_clarification = policy.clarifications.iter().any(|c| c.package == package);
+
+    let trimmed = version.strip_prefix('v').unwrap_or(version);
+    let parsed_version = match semver::Version::parse(trimmed) {
+        Ok(v) => v,
+        Err(_) if has_matching_clarification => {
+            return Err(PolicyError::InvalidClarificationVersion(
+                package.to_string(),
+                version.to_string(),
+            ));
+        }
+        Err(_) => return Ok(None),
+    };
+
+    policy
+        .clarifications
+        .iter()
+        .find(|c| c.package == package && c.version.matches(&parsed_version))
+        .map(|c| spdx::parse(&c.license).map_err(|e| PolicyError::Clarification(
+            package.to_string(),
+            version.to_string(),
+            e,
+        )))
+        .transpose()
+}
+
+/// A single policy violation: the package/version and the license that
+/// triggered it.
+#[derive(Debug, Clone)]
+pub struct Violation {
+    pub package: String,
+    pub version: String,
+    pub license: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}@{}: license '{}' is not allowed by policy",
+            self.package, self.version, self.license
+        )
+    }
+}
+
+fn is_leaf_compliant(policy: &Policy, license: &str) -> bool {
+    let allowed = policy.allow.iter().any(|a| a == license);
+    let denied = policy.deny.iter().any(|d| d == license);
+
+    if denied {
+        false
+    } else if allowed {
+        true
+    } else {
+        policy.default == DefaultAction::Allow
+    }
+}
+
+/// Recursively checks whether `expr` is compliant with `policy`, respecting
+/// the AST's structure: an `Or` is compliant if either branch is, while an
+/// `And` (or a `WITH` exception) requires every branch to be.
+fn is_compliant(policy: &Policy, expr: &SpdxExpr) -> bool {
+    match expr {
+        SpdxExpr::License(id) => is_leaf_compliant(policy, id),
+        SpdxExpr::Exception { license, .. } => is_compliant(policy, license),
+        SpdxExpr::And(lhs, rhs) => is_compliant(policy, lhs) && is_compliant(policy, rhs),
+        SpdxExpr::Or(lhs, rhs) => is_compliant(policy, lhs) || is_compliant(policy, rhs),
+    }
+}
+
+/// Finds a leaf license responsible for `expr` being non-compliant, for use
+/// in the violation message. Only meaningful when `is_compliant` is false.
+fn first_violating_leaf(policy: &Policy, expr: &SpdxExpr) -> Option<String> {
+    match expr {
+        SpdxExpr::License(id) => (!is_leaf_compliant(policy, id)).then(|| id.clone()),
+        SpdxExpr::Exception { license, .. } => first_violating_leaf(policy, license),
+        SpdxExpr::And(lhs, rhs) | SpdxExpr::Or(lhs, rhs) => {
+            first_violating_leaf(policy, lhs).or_else(|| first_violating_leaf(policy, rhs))
+        }
+    }
+}
+
+/// Evaluates `expr` against `policy`, respecting operator precedence: an
+/// `Or` passes if either branch is compliant, while `And`/`WITH` require
+/// every branch to be. Returns a violation naming an offending leaf license
+/// if the expression as a whole is non-compliant.
+pub fn evaluate(
+    policy: &Policy,
+    package: &str,
+    version: &str,
+    expr: &SpdxExpr,
+) -> Option<Violation> {
+    if is_compliant(policy, expr) {
+        return None;
+    }
+
+    let license = first_violating_leaf(policy, expr).unwrap_or_else(|| expr.to_string());
+
+    Some(Violation {
+        package: package.to_string(),
+        version: version.to_string(),
+        license,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(allow: &[&str], deny: &[&str], default: DefaultAction) -> Policy {
+        Policy {
+            allow: allow.iter().map(|s| s.to_string()).collect(),
+            deny: deny.iter().map(|s| s.to_string()).collect(),
+            default,
+            clarifications: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn or_passes_when_either_branch_is_compliant() {
+        let policy = policy(&["MIT"], &["GPL-3.0-only"], DefaultAction::Deny);
+        let expr = spdx::parse("MIT OR GPL-3.0-only").unwrap();
+
+        assert!(evaluate(&policy, "pkg", "1.0.0", &expr).is_none());
+    }
+
+    #[test]
+    fn or_fails_when_every_branch_is_denied() {
+        let policy = policy(&[], &["GPL-3.0-only", "AGPL-3.0-only"], DefaultAction::Allow);
+        let expr = spdx::parse("GPL-3.0-only OR AGPL-3.0-only").unwrap();
+
+        assert!(evaluate(&policy, "pkg", "1.0.0", &expr).is_some());
+    }
+
+    #[test]
+    fn and_fails_when_one_branch_is_denied() {
+        let policy = policy(&["MIT"], &["GPL-3.0-only"], DefaultAction::Allow);
+        let expr = spdx::parse("MIT AND GPL-3.0-only").unwrap();
+
+        assert!(evaluate(&policy, "pkg", "1.0.0", &expr).is_some());
+    }
+
+    #[test]
+    fn deny_wins_over_allow_for_the_same_license() {
+        let policy = policy(&["MIT"], &["MIT"], DefaultAction::Allow);
+        let expr = spdx::parse("MIT").unwrap();
+
+        assert!(evaluate(&policy, "pkg", "1.0.0", &expr).is_some());
+    }
+
+    #[test]
+    fn unmatched_license_follows_default_action() {
+        let policy = policy(&[], &[], DefaultAction::Deny);
+        let expr = spdx::parse("MIT").unwrap();
+
+        assert!(evaluate(&policy, "pkg", "1.0.0", &expr).is_some());
+    }
+
+    #[test]
+    fn clarified_license_accepts_leading_v_version() {
+        let mut policy = policy(&[], &[], DefaultAction::Deny);
+        policy.clarifications.push(Clarification {
+            package: "example".to_string(),
+            version: semver::VersionReq::parse("^1.2").unwrap(),
+            license: "MIT".to_string(),
+        });
+
+        let result = clarified_license(&policy, "example", Some("v1.2.3"));
+        assert_eq!(result.unwrap(), Some(SpdxExpr::License("MIT".to_string())));
+    }
+
+    #[test]
+    fn clarified_license_reports_unparseable_version_for_matching_package() {
+        let mut policy = policy(&[], &[], DefaultAction::Deny);
+        policy.clarifications.push(Clarification {
+            package: "example".to_string(),
+            version: semver::VersionReq::parse("^1.2").unwrap(),
+            license: "MIT".to_string(),
+        });
+
+        let err = clarified_license(&policy, "example", Some("not-a-version")).unwrap_err();
+        assert!(matches!(err, PolicyError::InvalidClarificationVersion(_, _)));
+    }
+}
@@ -0,0 +1,435 @@
+//! A small parser for SPDX license expressions.
+//!
+//! This module tokenizes and parses strings like
+//! `(MIT OR Apache-2.0) AND GPL-2.0-only WITH Classpath-exception-2.0` into an
+//! [`SpdxExpr`] AST, validating every license and exception identifier against
+//! a bundled list of known SPDX IDs. Operator precedence follows the SPDX
+//! specification: `WITH` binds tightest, then `AND`, then `OR`, with
+//! parentheses overriding the default grouping.
+
+use std::fmt;
+
+/// A parsed SPDX license expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpdxExpr {
+    /// A single license identifier, e.g. `MIT`.
+    License(String),
+    /// A license combined with an exception via `WITH`, e.g.
+    /// `GPL-2.0-only WITH Classpath-exception-2.0`.
+    Exception {
+        license: Box<SpdxExpr>,
+        exception: String,
+    },
+    /// Both sides are required (`AND`).
+    And(Box<SpdxExpr>, Box<SpdxExpr>),
+    /// Either side is acceptable (`OR`).
+    Or(Box<SpdxExpr>, Box<SpdxExpr>),
+}
+
+impl fmt::Display for SpdxExpr {
+    /// Canonical re-serialization of the expression, with parentheses
+    /// inserted only where required to preserve precedence.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, 0)
+    }
+}
+
+impl SpdxExpr {
+    fn write(&self, f: &mut fmt::Formatter<'_>, parent_prec: u8) -> fmt::Result {
+        match self {
+            SpdxExpr::License(id) => write!(f, "{}", id),
+            SpdxExpr::Exception { license, exception } => {
+                license.write(f, 2)?;
+                write!(f, " WITH {}", exception)
+            }
+            SpdxExpr::And(lhs, rhs) => {
+                let needs_parens = parent_prec > 1;
+                if needs_parens {
+                    write!(f, "(")?;
+                }
+                lhs.write(f, 1)?;
+                write!(f, " AND ")?;
+                rhs.write(f, 1)?;
+                if needs_parens {
+                    write!(f, ")")?;
+                }
+                Ok(())
+            }
+            SpdxExpr::Or(lhs, rhs) => {
+                let needs_parens = parent_prec > 0;
+                if needs_parens {
+                    write!(f, "(")?;
+                }
+                lhs.write(f, 0)?;
+                write!(f, " OR ")?;
+                rhs.write(f, 0)?;
+                if needs_parens {
+                    write!(f, ")")?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Collects the leaf license identifiers in deterministic, left-to-right
+    /// order, ignoring exceptions and operators. Used by the CSV/table layer
+    /// in place of naive string splitting.
+    pub fn flatten_licenses(&self) -> Vec<String> {
+        let mut out = Vec::new();
+        self.flatten_into(&mut out);
+        out
+    }
+
+    fn flatten_into(&self, out: &mut Vec<String>) {
+        match self {
+            SpdxExpr::License(id) => out.push(id.clone()),
+            SpdxExpr::Exception { license, .. } => license.flatten_into(out),
+            SpdxExpr::And(lhs, rhs) | SpdxExpr::Or(lhs, rhs) => {
+                lhs.flatten_into(out);
+                rhs.flatten_into(out);
+            }
+        }
+    }
+}
+
+/// Errors produced while parsing or validating an SPDX expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpdxError {
+    /// An identifier that isn't in the bundled list of known SPDX licenses.
+    UnknownLicenseId(String),
+    /// An identifier after `WITH` that isn't in the bundled list of known
+    /// SPDX exceptions.
+    UnknownExceptionId(String),
+    /// The expression is malformed, e.g. two identifiers with no operator
+    /// between them, or a `WITH` whose right-hand side isn't a bare
+    /// exception identifier.
+    InvalidStructure(String),
+}
+
+impl fmt::Display for SpdxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpdxError::UnknownLicenseId(id) => write!(f, "unknown SPDX license id: {}", id),
+            SpdxError::UnknownExceptionId(id) => write!(f, "unknown SPDX exception id: {}", id),
+            SpdxError::InvalidStructure(msg) => write!(f, "invalid SPDX expression: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for SpdxError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Ident(String),
+    And,
+    Or,
+    With,
+    LParen,
+    RParen,
+}
+
+fn tokenize(expr: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = expr.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        if c == '(' {
+            tokens.push(Token::LParen);
+            chars.next();
+            continue;
+        }
+        if c == ')' {
+            tokens.push(Token::RParen);
+            chars.next();
+            continue;
+        }
+
+        let mut word = String::new();
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() || c == '(' || c == ')' {
+                break;
+            }
+            word.push(c);
+            chars.next();
+        }
+
+        tokens.push(match word.as_str() {
+            "AND" => Token::And,
+            "OR" => Token::Or,
+            "WITH" => Token::With,
+            _ => Token::Ident(word),
+        });
+    }
+
+    tokens
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let tok = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        tok
+    }
+
+    // or_expr := and_expr (OR and_expr)*
+    fn parse_or(&mut self) -> Result<SpdxExpr, SpdxError> {
+        let mut lhs = self.parse_and()?;
+        while matches!(self.peek(), Some(Token::Or)) {
+            self.advance();
+            let rhs = self.parse_and()?;
+            lhs = SpdxExpr::Or(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    // and_expr := with_expr (AND with_expr)*
+    fn parse_and(&mut self) -> Result<SpdxExpr, SpdxError> {
+        let mut lhs = self.parse_with()?;
+        while matches!(self.peek(), Some(Token::And)) {
+            self.advance();
+            let rhs = self.parse_with()?;
+            lhs = SpdxExpr::And(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    // with_expr := primary (WITH IDENT)?
+    fn parse_with(&mut self) -> Result<SpdxExpr, SpdxError> {
+        let primary = self.parse_primary()?;
+        if matches!(self.peek(), Some(Token::With)) {
+            self.advance();
+            match self.advance() {
+                Some(Token::Ident(id)) => {
+                    if !is_known_exception(&id) {
+                        return Err(SpdxError::UnknownExceptionId(id));
+                    }
+                    Ok(SpdxExpr::Exception {
+                        license: Box::new(primary),
+                        exception: id,
+                    })
+                }
+                other => Err(SpdxError::InvalidStructure(format!(
+                    "expected an exception id after WITH, found {:?}",
+                    other
+                ))),
+            }
+        } else {
+            Ok(primary)
+        }
+    }
+
+    // primary := IDENT | '(' or_expr ')'
+    fn parse_primary(&mut self) -> Result<SpdxExpr, SpdxError> {
+        match self.advance() {
+            Some(Token::Ident(id)) => {
+                if !is_known_license(&id) {
+                    return Err(SpdxError::UnknownLicenseId(id));
+                }
+                Ok(SpdxExpr::License(id))
+            }
+            Some(Token::LParen) => {
+                let inner = self.parse_or()?;
+                match self.advance() {
+                    Some(Token::RParen) => Ok(inner),
+                    other => Err(SpdxError::InvalidStructure(format!(
+                        "expected closing parenthesis, found {:?}",
+                        other
+                    ))),
+                }
+            }
+            other => Err(SpdxError::InvalidStructure(format!(
+                "expected a license id or '(', found {:?}",
+                other
+            ))),
+        }
+    }
+}
+
+/// Parses an SPDX license expression into an [`SpdxExpr`] AST, validating
+/// every identifier along the way.
+pub fn parse(expr: &str) -> Result<SpdxExpr, SpdxError> {
+    let tokens = tokenize(expr);
+    if tokens.is_empty() {
+        return Err(SpdxError::InvalidStructure("empty expression".to_string()));
+    }
+
+    let mut parser = Parser { tokens, pos: 0 };
+    let ast = parser.parse_or()?;
+
+    if parser.pos != parser.tokens.len() {
+        return Err(SpdxError::InvalidStructure(format!(
+            "unexpected trailing token at position {}",
+            parser.pos
+        )));
+    }
+
+    Ok(ast)
+}
+
+/// Returns true if `id` is a known SPDX license identifier.
+pub fn is_known_license(id: &str) -> bool {
+    KNOWN_LICENSES.contains(&id)
+}
+
+/// Returns true if `id` is a known SPDX exception identifier.
+pub fn is_known_exception(id: &str) -> bool {
+    KNOWN_EXCEPTIONS.contains(&id)
+}
+
+/// A subset of the SPDX license list (https://spdx.org/licenses/) covering
+/// the identifiers most commonly seen in Syft SBOMs. Extend as needed.
+const KNOWN_LICENSES: &[&str] = &[
+    "0BSD",
+    "AFL-3.0",
+    "AGPL-3.0-only",
+    "AGPL-3.0-or-later",
+    "Apache-1.1",
+    "Apache-2.0",
+    "Artistic-2.0",
+    "BSD-2-Clause",
+    "BSD-3-Clause",
+    "BSD-4-Clause",
+    "BSL-1.0",
+    "CC0-1.0",
+    "CC-BY-4.0",
+    "CC-BY-SA-4.0",
+    "CDDL-1.0",
+    "CDDL-1.1",
+    "CPL-1.0",
+    "EPL-1.0",
+    "EPL-2.0",
+    "EUPL-1.1",
+    "EUPL-1.2",
+    "GFDL-1.3-only",
+    "GPL-1.0-only",
+    "GPL-1.0-or-later",
+    "GPL-2.0-only",
+    "GPL-2.0-or-later",
+    "GPL-3.0-only",
+    "GPL-3.0-or-later",
+    "ISC",
+    "LGPL-2.0-only",
+    "LGPL-2.0-or-later",
+    "LGPL-2.1-only",
+    "LGPL-2.1-or-later",
+    "LGPL-3.0-only",
+    "LGPL-3.0-or-later",
+    "MIT",
+    "MIT-0",
+    "MPL-1.0",
+    "MPL-1.1",
+    "MPL-2.0",
+    "MS-PL",
+    "MS-RL",
+    "NCSA",
+    "OFL-1.1",
+    "OpenSSL",
+    "PostgreSQL",
+    "Python-2.0",
+    "Ruby",
+    "Unicode-DFS-2016",
+    "Unlicense",
+    "Vim",
+    "WTFPL",
+    "X11",
+    "Zlib",
+];
+
+/// A subset of the SPDX exception list
+/// (https://spdx.org/licenses/exceptions-index.html).
+const KNOWN_EXCEPTIONS: &[&str] = &[
+    "Classpath-exception-2.0",
+    "GCC-exception-2.0",
+    "GCC-exception-3.1",
+    "LLVM-exception",
+    "LGPL-3.0-linking-exception",
+    "OpenJDK-assembly-exception-1.0",
+    "Linux-syscall-note",
+    "u-boot-exception-2.0",
+    "WxWindows-exception-3.1",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_reserializes_precedence() {
+        let expr = parse("(MIT OR Apache-2.0) AND GPL-2.0-only WITH Classpath-exception-2.0")
+            .expect("valid expression");
+
+        assert_eq!(
+            expr.to_string(),
+            "(MIT OR Apache-2.0) AND GPL-2.0-only WITH Classpath-exception-2.0"
+        );
+    }
+
+    #[test]
+    fn with_binds_tighter_than_and() {
+        let expr = parse("MIT AND GPL-2.0-only WITH Classpath-exception-2.0").unwrap();
+
+        assert_eq!(
+            expr,
+            SpdxExpr::And(
+                Box::new(SpdxExpr::License("MIT".to_string())),
+                Box::new(SpdxExpr::Exception {
+                    license: Box::new(SpdxExpr::License("GPL-2.0-only".to_string())),
+                    exception: "Classpath-exception-2.0".to_string(),
+                }),
+            )
+        );
+    }
+
+    #[test]
+    fn and_binds_tighter_than_or() {
+        let expr = parse("MIT OR Apache-2.0 AND ISC").unwrap();
+
+        assert_eq!(
+            expr,
+            SpdxExpr::Or(
+                Box::new(SpdxExpr::License("MIT".to_string())),
+                Box::new(SpdxExpr::And(
+                    Box::new(SpdxExpr::License("Apache-2.0".to_string())),
+                    Box::new(SpdxExpr::License("ISC".to_string())),
+                )),
+            )
+        );
+        // Without the parentheses this reordering implies, re-serializing
+        // should not need any.
+        assert_eq!(expr.to_string(), "MIT OR Apache-2.0 AND ISC");
+    }
+
+    #[test]
+    fn rejects_unknown_license_id() {
+        let err = parse("NotARealLicense").unwrap_err();
+        assert_eq!(err, SpdxError::UnknownLicenseId("NotARealLicense".to_string()));
+    }
+
+    #[test]
+    fn rejects_unknown_exception_id() {
+        let err = parse("GPL-2.0-only WITH NotARealException").unwrap_err();
+        assert_eq!(
+            err,
+            SpdxError::UnknownExceptionId("NotARealException".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_two_licenses_with_no_operator() {
+        let err = parse("MIT Apache-2.0").unwrap_err();
+        assert!(matches!(err, SpdxError::InvalidStructure(_)));
+    }
+}